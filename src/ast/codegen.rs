@@ -0,0 +1,130 @@
+use std::collections::HashSet;
+
+use crate::ast::{
+    ASTAssignmentStatement, ASTBinaryExpression, ASTBinaryOperatorKind, ASTBooleanExpression,
+    ASTExpression, ASTNumberExpression, ASTParenthesizedExpression, ASTStatement,
+    ASTStatementKind, ASTUnaryExpression, ASTUnaryOperatorKind, ASTVariableExpression, ASTVisitor,
+};
+
+pub struct CGenerator {
+    last_expr: String,
+    declared: HashSet<String>,
+    body: String,
+}
+
+impl CGenerator {
+    pub fn new() -> Self {
+        Self {
+            last_expr: String::new(),
+            declared: HashSet::new(),
+            body: String::new(),
+        }
+    }
+
+    pub fn generate(ast: &crate::ast::Ast) -> String {
+        let mut generator = Self::new();
+        ast.visit(&mut generator);
+        generator.finish()
+    }
+
+    fn finish(self) -> String {
+        format!(
+            "#include <math.h>\n#include <stdbool.h>\n\nint main(void) {{\n    double __result = 0;\n{}    return (int) __result;\n}}\n",
+            self.body
+        )
+    }
+}
+
+impl ASTVisitor for CGenerator {
+    fn visit_statement(&mut self, statement: &ASTStatement) {
+        match &statement.kind {
+            ASTStatementKind::Expression(expr) => {
+                self.visit_expression(expr);
+                let value = self.last_expr.clone();
+                self.body.push_str(&format!("    __result = {};\n", value));
+            }
+            ASTStatementKind::Assignment(assignment) => {
+                self.visit_assignment_statement(assignment);
+            }
+        }
+    }
+
+    fn visit_assignment_statement(&mut self, assignment_statement: &ASTAssignmentStatement) {
+        self.visit_expression(&assignment_statement.value);
+        let value = self.last_expr.clone();
+        let declaration = if self.declared.insert(assignment_statement.name.clone()) {
+            "double "
+        } else {
+            ""
+        };
+        self.body.push_str(&format!(
+            "    {}{} = {};\n",
+            declaration, assignment_statement.name, value
+        ));
+        self.body
+            .push_str(&format!("    __result = {};\n", assignment_statement.name));
+    }
+
+    fn visit_expression(&mut self, expression: &ASTExpression) {
+        ASTVisitor::do_visit_expression(self, expression);
+    }
+
+    fn visit_number(&mut self, expression: &ASTNumberExpression) {
+        self.last_expr = expression.number.to_string();
+    }
+
+    fn visit_boolean_expression(&mut self, boolean_expression: &ASTBooleanExpression) {
+        self.last_expr = boolean_expression.value.to_string();
+    }
+
+    fn visit_binary_expression(&mut self, binary_expression: &ASTBinaryExpression) {
+        self.visit_expression(&binary_expression.left);
+        let left = self.last_expr.clone();
+        self.visit_expression(&binary_expression.right);
+        let right = self.last_expr.clone();
+
+        if let ASTBinaryOperatorKind::Caret = binary_expression.operator.kind {
+            self.last_expr = format!("pow({}, {})", left, right);
+            return;
+        }
+
+        let operator = match binary_expression.operator.kind {
+            ASTBinaryOperatorKind::Plus => "+",
+            ASTBinaryOperatorKind::Minus => "-",
+            ASTBinaryOperatorKind::Multiply => "*",
+            ASTBinaryOperatorKind::Divide => "/",
+            ASTBinaryOperatorKind::Equals => "==",
+            ASTBinaryOperatorKind::NotEquals => "!=",
+            ASTBinaryOperatorKind::Less => "<",
+            ASTBinaryOperatorKind::LessEqual => "<=",
+            ASTBinaryOperatorKind::Greater => ">",
+            ASTBinaryOperatorKind::GreaterEqual => ">=",
+            ASTBinaryOperatorKind::And => "&&",
+            ASTBinaryOperatorKind::Or => "||",
+            ASTBinaryOperatorKind::Caret => unreachable!("handled above"),
+        };
+        self.last_expr = format!("{} {} {}", left, operator, right);
+    }
+
+    fn visit_parenthesized_expression(
+        &mut self,
+        parenthesized_expression: &ASTParenthesizedExpression,
+    ) {
+        self.visit_expression(&parenthesized_expression.expression);
+        self.last_expr = format!("({})", self.last_expr);
+    }
+
+    fn visit_unary_expression(&mut self, unary_expression: &ASTUnaryExpression) {
+        self.visit_expression(&unary_expression.operand);
+        let operand = self.last_expr.clone();
+        let operator = match unary_expression.operator.kind {
+            ASTUnaryOperatorKind::Minus => "-",
+            ASTUnaryOperatorKind::Bang => "!",
+        };
+        self.last_expr = format!("{}{}", operator, operand);
+    }
+
+    fn visit_variable_expression(&mut self, variable_expression: &ASTVariableExpression) {
+        self.last_expr = variable_expression.name.clone();
+    }
+}
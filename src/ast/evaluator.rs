@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::ast::lexer::TextSpan;
+use crate::ast::{
+    ASTAssignmentStatement, ASTBinaryExpression, ASTBinaryOperatorKind, ASTBooleanExpression,
+    ASTNumberExpression, ASTParenthesizedExpression, ASTUnaryExpression, ASTUnaryOperatorKind,
+    ASTVariableExpression, ASTVisitor,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Number(f64),
+    Boolean(bool),
+}
+
+impl Value {
+    fn type_name(&self) -> &'static str {
+        match self {
+            Value::Number(_) => "number",
+            Value::Boolean(_) => "boolean",
+        }
+    }
+
+    fn as_number(&self, span: &TextSpan) -> Result<f64, EvaluationError> {
+        match self {
+            Value::Number(value) => Ok(*value),
+            Value::Boolean(_) => Err(EvaluationError::TypeMismatch {
+                expected: "number",
+                found: *self,
+                span: span.clone(),
+            }),
+        }
+    }
+
+    fn as_boolean(&self, span: &TextSpan) -> Result<bool, EvaluationError> {
+        match self {
+            Value::Boolean(value) => Ok(*value),
+            Value::Number(_) => Err(EvaluationError::TypeMismatch {
+                expected: "boolean",
+                found: *self,
+                span: span.clone(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Number(value) => write!(f, "{}", value),
+            Value::Boolean(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum EvaluationError {
+    DivisionByZero {
+        span: TextSpan,
+    },
+    TypeMismatch {
+        expected: &'static str,
+        found: Value,
+        span: TextSpan,
+    },
+    UndefinedVariable {
+        name: String,
+        span: TextSpan,
+    },
+}
+
+impl fmt::Display for EvaluationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvaluationError::DivisionByZero { span } => {
+                write!(
+                    f,
+                    "division by zero at {}..{} (`{}`, {} byte{})",
+                    span.start,
+                    span.end,
+                    span.literal,
+                    span.length(),
+                    if span.length() == 1 { "" } else { "s" }
+                )
+            }
+            EvaluationError::TypeMismatch {
+                expected,
+                found,
+                span,
+            } => write!(
+                f,
+                "type mismatch at {}..{} (`{}`, {} byte{}): expected {}, found {}",
+                span.start,
+                span.end,
+                span.literal,
+                span.length(),
+                if span.length() == 1 { "" } else { "s" },
+                expected,
+                found.type_name()
+            ),
+            EvaluationError::UndefinedVariable { name, span } => write!(
+                f,
+                "undefined variable `{}` at {}..{} (`{}`, {} byte{})",
+                name,
+                span.start,
+                span.end,
+                span.literal,
+                span.length(),
+                if span.length() == 1 { "" } else { "s" }
+            ),
+        }
+    }
+}
+
+pub struct ASTEvaluator {
+    pub last_value: Option<Result<Value, EvaluationError>>,
+    variables: HashMap<String, Value>,
+}
+
+impl ASTEvaluator {
+    pub fn new() -> Self {
+        Self {
+            last_value: None,
+            variables: HashMap::new(),
+        }
+    }
+
+    fn eval_expression(
+        &mut self,
+        expression: &crate::ast::ASTExpression,
+    ) -> Result<Value, EvaluationError> {
+        self.visit_expression(expression);
+        self.last_value.clone().unwrap()
+    }
+}
+
+impl ASTVisitor for ASTEvaluator {
+    fn visit_statement(&mut self, statement: &crate::ast::ASTStatement) {
+        ASTVisitor::do_visit_statement(self, statement);
+    }
+
+    fn visit_assignment_statement(&mut self, assignment_statement: &ASTAssignmentStatement) {
+        let value = match self.eval_expression(&assignment_statement.value) {
+            Ok(value) => value,
+            Err(error) => {
+                self.last_value = Some(Err(error));
+                return;
+            }
+        };
+        self.variables
+            .insert(assignment_statement.name.clone(), value);
+        self.last_value = Some(Ok(value));
+    }
+
+    fn visit_expression(&mut self, expression: &crate::ast::ASTExpression) {
+        ASTVisitor::do_visit_expression(self, expression);
+    }
+
+    fn visit_number(&mut self, expression: &ASTNumberExpression) {
+        self.last_value = Some(Ok(Value::Number(expression.number)));
+    }
+
+    fn visit_boolean_expression(&mut self, boolean_expression: &ASTBooleanExpression) {
+        self.last_value = Some(Ok(Value::Boolean(boolean_expression.value)));
+    }
+
+    fn visit_binary_expression(&mut self, binary_expression: &ASTBinaryExpression) {
+        let left = match self.eval_expression(&binary_expression.left) {
+            Ok(value) => value,
+            Err(error) => {
+                self.last_value = Some(Err(error));
+                return;
+            }
+        };
+        let right = match self.eval_expression(&binary_expression.right) {
+            Ok(value) => value,
+            Err(error) => {
+                self.last_value = Some(Err(error));
+                return;
+            }
+        };
+
+        let span = binary_expression.operator.span();
+        self.last_value = Some(self.eval_binary(
+            &binary_expression.operator.kind,
+            left,
+            right,
+            span,
+        ));
+    }
+
+    fn visit_parenthesized_expression(
+        &mut self,
+        parenthesized_expression: &ASTParenthesizedExpression,
+    ) {
+        self.visit_expression(&parenthesized_expression.expression);
+    }
+
+    fn visit_unary_expression(&mut self, unary_expression: &ASTUnaryExpression) {
+        let operand = match self.eval_expression(&unary_expression.operand) {
+            Ok(value) => value,
+            Err(error) => {
+                self.last_value = Some(Err(error));
+                return;
+            }
+        };
+
+        let span = unary_expression.operator.span();
+        self.last_value = Some(match unary_expression.operator.kind {
+            ASTUnaryOperatorKind::Minus => operand.as_number(span).map(|value| Value::Number(-value)),
+            ASTUnaryOperatorKind::Bang => operand.as_boolean(span).map(|value| Value::Boolean(!value)),
+        });
+    }
+
+    fn visit_variable_expression(&mut self, variable_expression: &ASTVariableExpression) {
+        self.last_value = Some(
+            self.variables
+                .get(&variable_expression.name)
+                .copied()
+                .ok_or_else(|| EvaluationError::UndefinedVariable {
+                    name: variable_expression.name.clone(),
+                    span: variable_expression.span().clone(),
+                }),
+        );
+    }
+}
+
+impl ASTEvaluator {
+    fn eval_binary(
+        &self,
+        operator: &ASTBinaryOperatorKind,
+        left: Value,
+        right: Value,
+        span: &TextSpan,
+    ) -> Result<Value, EvaluationError> {
+        Ok(match operator {
+            ASTBinaryOperatorKind::Plus => {
+                Value::Number(left.as_number(span)? + right.as_number(span)?)
+            }
+            ASTBinaryOperatorKind::Minus => {
+                Value::Number(left.as_number(span)? - right.as_number(span)?)
+            }
+            ASTBinaryOperatorKind::Multiply => {
+                Value::Number(left.as_number(span)? * right.as_number(span)?)
+            }
+            ASTBinaryOperatorKind::Divide => {
+                let right = right.as_number(span)?;
+                if right == 0.0 {
+                    return Err(EvaluationError::DivisionByZero { span: span.clone() });
+                }
+                Value::Number(left.as_number(span)? / right)
+            }
+            ASTBinaryOperatorKind::Equals => Value::Boolean(left == right),
+            ASTBinaryOperatorKind::NotEquals => Value::Boolean(left != right),
+            ASTBinaryOperatorKind::Less => {
+                Value::Boolean(left.as_number(span)? < right.as_number(span)?)
+            }
+            ASTBinaryOperatorKind::LessEqual => {
+                Value::Boolean(left.as_number(span)? <= right.as_number(span)?)
+            }
+            ASTBinaryOperatorKind::Greater => {
+                Value::Boolean(left.as_number(span)? > right.as_number(span)?)
+            }
+            ASTBinaryOperatorKind::GreaterEqual => {
+                Value::Boolean(left.as_number(span)? >= right.as_number(span)?)
+            }
+            ASTBinaryOperatorKind::And => {
+                Value::Boolean(left.as_boolean(span)? && right.as_boolean(span)?)
+            }
+            ASTBinaryOperatorKind::Or => {
+                Value::Boolean(left.as_boolean(span)? || right.as_boolean(span)?)
+            }
+            ASTBinaryOperatorKind::Caret => {
+                Value::Number(left.as_number(span)?.powf(right.as_number(span)?))
+            }
+        })
+    }
+}
@@ -0,0 +1,192 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    Number(f64),
+    Identifier(String),
+    Plus,
+    Minus,
+    Asterisk,
+    Slash,
+    Caret,
+    Bang,
+    Equals,
+    EqualsEquals,
+    BangEquals,
+    LessThan,
+    LessThanEquals,
+    GreaterThan,
+    GreaterThanEquals,
+    AmpersandAmpersand,
+    PipePipe,
+    True,
+    False,
+    LeftParen,
+    RightParen,
+    Whitespace,
+    Bad,
+    Eof,
+}
+
+#[derive(Debug, Clone)]
+pub struct TextSpan {
+    pub start: usize,
+    pub end: usize,
+    pub literal: String,
+}
+
+impl TextSpan {
+    pub fn new(start: usize, end: usize, literal: String) -> Self {
+        Self { start, end, literal }
+    }
+
+    pub fn length(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: TextSpan,
+}
+
+impl Token {
+    pub fn new(kind: TokenKind, span: TextSpan) -> Self {
+        Self { kind, span }
+    }
+}
+
+pub struct Lexer<'a> {
+    input: &'a str,
+    current_pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            current_pos: 0,
+        }
+    }
+
+    pub fn next_token(&mut self) -> Option<Token> {
+        if self.current_pos == self.input.len() {
+            self.current_pos += 1;
+            return Some(Token::new(
+                TokenKind::Eof,
+                TextSpan::new(self.current_pos, self.current_pos, '\0'.to_string()),
+            ));
+        }
+
+        let c = self.current_char()?;
+        let start = self.current_pos;
+
+        let kind = if Self::is_number_start(&c) {
+            TokenKind::Number(self.consume_number())
+        } else if Self::is_identifier_start(&c) {
+            match self.consume_identifier().as_str() {
+                "true" => TokenKind::True,
+                "false" => TokenKind::False,
+                identifier => TokenKind::Identifier(identifier.to_string()),
+            }
+        } else if c.is_whitespace() {
+            self.consume();
+            TokenKind::Whitespace
+        } else {
+            self.consume_punctuation()
+        };
+
+        let end = self.current_pos;
+        let literal = self.input[start..end].to_string();
+        Some(Token::new(kind, TextSpan::new(start, end, literal)))
+    }
+
+    fn is_number_start(c: &char) -> bool {
+        c.is_ascii_digit()
+    }
+
+    fn is_identifier_start(c: &char) -> bool {
+        c.is_alphabetic() || *c == '_'
+    }
+
+    fn current_char(&self) -> Option<char> {
+        self.input.chars().nth(self.current_pos)
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.input.chars().nth(self.current_pos + 1)
+    }
+
+    fn consume(&mut self) -> Option<char> {
+        let c = self.current_char()?;
+        self.current_pos += 1;
+        Some(c)
+    }
+
+    fn consume_number(&mut self) -> f64 {
+        let mut number = String::new();
+        let mut seen_dot = false;
+        while let Some(c) = self.current_char() {
+            if c.is_ascii_digit() {
+                number.push(c);
+                self.consume();
+            } else if c == '.' && !seen_dot && matches!(self.peek_char(), Some(next) if next.is_ascii_digit()) {
+                seen_dot = true;
+                number.push(c);
+                self.consume();
+            } else {
+                break;
+            }
+        }
+        number.parse().unwrap()
+    }
+
+    fn consume_identifier(&mut self) -> String {
+        let mut identifier = String::new();
+        while let Some(c) = self.current_char() {
+            if c.is_alphanumeric() || c == '_' {
+                identifier.push(c);
+                self.consume();
+            } else {
+                break;
+            }
+        }
+        identifier
+    }
+
+    fn consume_punctuation(&mut self) -> TokenKind {
+        let c = self.consume().unwrap();
+        match c {
+            '+' => TokenKind::Plus,
+            '-' => TokenKind::Minus,
+            '*' => TokenKind::Asterisk,
+            '/' => TokenKind::Slash,
+            '^' => TokenKind::Caret,
+            '!' => self.consume_if_eq_follows(TokenKind::BangEquals, TokenKind::Bang),
+            '=' => self.consume_if_eq_follows(TokenKind::EqualsEquals, TokenKind::Equals),
+            '<' => self.consume_if_eq_follows(TokenKind::LessThanEquals, TokenKind::LessThan),
+            '>' => {
+                self.consume_if_eq_follows(TokenKind::GreaterThanEquals, TokenKind::GreaterThan)
+            }
+            '&' if self.current_char() == Some('&') => {
+                self.consume();
+                TokenKind::AmpersandAmpersand
+            }
+            '|' if self.current_char() == Some('|') => {
+                self.consume();
+                TokenKind::PipePipe
+            }
+            '(' => TokenKind::LeftParen,
+            ')' => TokenKind::RightParen,
+            _ => TokenKind::Bad,
+        }
+    }
+
+    fn consume_if_eq_follows(&mut self, matched: TokenKind, unmatched: TokenKind) -> TokenKind {
+        if self.current_char() == Some('=') {
+            self.consume();
+            matched
+        } else {
+            unmatched
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use self::lexer::Token;
 
+pub mod codegen;
+pub mod evaluator;
 pub mod lexer;
 pub mod parser;
 
+#[derive(Debug, PartialEq)]
 pub struct Ast {
     pub statements: Vec<ASTStatement>,
 }
@@ -28,12 +31,26 @@ impl Ast {
         let mut printer = ASTPrinter { indent: 0 };
         self.visit(&mut printer)
     }
+
+    pub fn to_source(&self) -> String {
+        let mut printer = ASTSourcePrinter::new();
+        let mut source = String::new();
+        for statement in &self.statements {
+            printer.visit_statement(statement);
+            source.push_str(&printer.last);
+            source.push('\n');
+        }
+        source
+    }
 }
 
 pub trait ASTVisitor {
     fn do_visit_statement(&mut self, statement: &ASTStatement) {
         match &statement.kind {
             ASTStatementKind::Expression(expr) => self.visit_expression(expr),
+            ASTStatementKind::Assignment(assignment) => {
+                self.visit_assignment_statement(assignment);
+            }
         }
     }
     fn visit_statement(&mut self, statement: &ASTStatement);
@@ -46,15 +63,28 @@ pub trait ASTVisitor {
             ASTExpressionKind::Parenthesized(expr) => {
                 self.visit_parenthesized_expression(expr);
             }
+            ASTExpressionKind::Unary(expr) => {
+                self.visit_unary_expression(expr);
+            }
+            ASTExpressionKind::Variable(expr) => {
+                self.visit_variable_expression(expr);
+            }
+            ASTExpressionKind::Boolean(expr) => {
+                self.visit_boolean_expression(expr);
+            }
         }
     }
     fn visit_expression(&mut self, expression: &ASTExpression);
+    fn visit_assignment_statement(&mut self, assignment_statement: &ASTAssignmentStatement);
     fn visit_number(&mut self, expression: &ASTNumberExpression);
+    fn visit_boolean_expression(&mut self, boolean_expression: &ASTBooleanExpression);
     fn visit_binary_expression(&mut self, binary_expression: &ASTBinaryExpression);
     fn visit_parenthesized_expression(
         &mut self,
         parenthesized_expression: &ASTParenthesizedExpression,
     );
+    fn visit_unary_expression(&mut self, unary_expression: &ASTUnaryExpression);
+    fn visit_variable_expression(&mut self, variable_expression: &ASTVariableExpression);
 }
 
 pub struct ASTPrinter {
@@ -82,6 +112,10 @@ impl ASTVisitor for ASTPrinter {
         self.print_with_indent(&format!("Number: {}", expression.number));
     }
 
+    fn visit_boolean_expression(&mut self, boolean_expression: &ASTBooleanExpression) {
+        self.print_with_indent(&format!("Boolean: {}", boolean_expression.value));
+    }
+
     fn visit_binary_expression(&mut self, binary_expression: &ASTBinaryExpression) {
         self.print_with_indent("Binary Expression:");
         self.indent += LEVEL_INDENT;
@@ -100,6 +134,26 @@ impl ASTVisitor for ASTPrinter {
         self.visit_expression(&parenthesized_expression.expression);
         self.indent -= LEVEL_INDENT;
     }
+
+    fn visit_unary_expression(&mut self, unary_expression: &ASTUnaryExpression) {
+        self.print_with_indent("Unary Expression:");
+        self.indent += LEVEL_INDENT;
+        self.print_with_indent(&format!("Operator: {:?}", unary_expression.operator.kind));
+        self.visit_expression(&unary_expression.operand);
+        self.indent -= LEVEL_INDENT;
+    }
+
+    fn visit_variable_expression(&mut self, variable_expression: &ASTVariableExpression) {
+        self.print_with_indent(&format!("Variable: {}", variable_expression.name));
+    }
+
+    fn visit_assignment_statement(&mut self, assignment_statement: &ASTAssignmentStatement) {
+        self.print_with_indent("Assignment Statement:");
+        self.indent += LEVEL_INDENT;
+        self.print_with_indent(&format!("Name: {}", assignment_statement.name));
+        self.visit_expression(&assignment_statement.value);
+        self.indent -= LEVEL_INDENT;
+    }
 }
 
 impl ASTPrinter {
@@ -111,10 +165,140 @@ impl ASTPrinter {
     }
 }
 
+const ATOM_PRECEDENCE: u8 = u8::MAX;
+const UNARY_PRECEDENCE: u8 = 8;
+
+pub struct ASTSourcePrinter {
+    last: String,
+    last_precedence: u8,
+}
+
+impl ASTSourcePrinter {
+    pub fn new() -> Self {
+        Self {
+            last: String::new(),
+            last_precedence: ATOM_PRECEDENCE,
+        }
+    }
+}
+
+impl ASTVisitor for ASTSourcePrinter {
+    fn visit_statement(&mut self, statement: &ASTStatement) {
+        ASTVisitor::do_visit_statement(self, statement);
+    }
+
+    fn visit_assignment_statement(&mut self, assignment_statement: &ASTAssignmentStatement) {
+        self.visit_expression(&assignment_statement.value);
+        self.last = format!("{} = {}", assignment_statement.name, self.last);
+        self.last_precedence = ATOM_PRECEDENCE;
+    }
+
+    fn visit_expression(&mut self, expression: &ASTExpression) {
+        ASTVisitor::do_visit_expression(self, expression);
+    }
+
+    fn visit_number(&mut self, expression: &ASTNumberExpression) {
+        self.last = expression.number.to_string();
+        self.last_precedence = ATOM_PRECEDENCE;
+    }
+
+    fn visit_boolean_expression(&mut self, boolean_expression: &ASTBooleanExpression) {
+        self.last = boolean_expression.value.to_string();
+        self.last_precedence = ATOM_PRECEDENCE;
+    }
+
+    fn visit_variable_expression(&mut self, variable_expression: &ASTVariableExpression) {
+        self.last = variable_expression.name.clone();
+        self.last_precedence = ATOM_PRECEDENCE;
+    }
+
+    fn visit_parenthesized_expression(
+        &mut self,
+        parenthesized_expression: &ASTParenthesizedExpression,
+    ) {
+        // Parentheses are re-derived from precedence, not copied from the source.
+        self.visit_expression(&parenthesized_expression.expression);
+    }
+
+    fn visit_unary_expression(&mut self, unary_expression: &ASTUnaryExpression) {
+        self.visit_expression(&unary_expression.operand);
+        let operand = if self.last_precedence < UNARY_PRECEDENCE {
+            format!("({})", self.last)
+        } else {
+            self.last.clone()
+        };
+
+        let operator = match unary_expression.operator.kind {
+            ASTUnaryOperatorKind::Minus => "-",
+            ASTUnaryOperatorKind::Bang => "!",
+        };
+        self.last = format!("{}{}", operator, operand);
+        self.last_precedence = UNARY_PRECEDENCE;
+    }
+
+    fn visit_binary_expression(&mut self, binary_expression: &ASTBinaryExpression) {
+        self.visit_expression(&binary_expression.left);
+        let left = self.last.clone();
+        let left_precedence = self.last_precedence;
+
+        self.visit_expression(&binary_expression.right);
+        let right = self.last.clone();
+        let right_precedence = self.last_precedence;
+
+        let precedence = binary_expression.operator.precedence();
+        let associativity = binary_expression.operator.associativity();
+
+        let left_needs_parens = left_precedence < precedence
+            || (left_precedence == precedence
+                && associativity == ASTBinaryOperatorAssociativity::Right);
+        let right_needs_parens = right_precedence < precedence
+            || (right_precedence == precedence
+                && associativity == ASTBinaryOperatorAssociativity::Left);
+
+        let left = if left_needs_parens {
+            format!("({})", left)
+        } else {
+            left
+        };
+        let right = if right_needs_parens {
+            format!("({})", right)
+        } else {
+            right
+        };
+
+        let operator = match binary_expression.operator.kind {
+            ASTBinaryOperatorKind::Plus => "+",
+            ASTBinaryOperatorKind::Minus => "-",
+            ASTBinaryOperatorKind::Multiply => "*",
+            ASTBinaryOperatorKind::Divide => "/",
+            ASTBinaryOperatorKind::Caret => "^",
+            ASTBinaryOperatorKind::Equals => "==",
+            ASTBinaryOperatorKind::NotEquals => "!=",
+            ASTBinaryOperatorKind::Less => "<",
+            ASTBinaryOperatorKind::LessEqual => "<=",
+            ASTBinaryOperatorKind::Greater => ">",
+            ASTBinaryOperatorKind::GreaterEqual => ">=",
+            ASTBinaryOperatorKind::And => "&&",
+            ASTBinaryOperatorKind::Or => "||",
+        };
+        self.last = format!("{} {} {}", left, operator, right);
+        self.last_precedence = precedence;
+    }
+}
+
+#[derive(Debug, PartialEq)]
 pub enum ASTStatementKind {
     Expression(ASTExpression),
+    Assignment(ASTAssignmentStatement),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ASTAssignmentStatement {
+    name: String,
+    value: ASTExpression,
 }
 
+#[derive(Debug, PartialEq)]
 pub struct ASTStatement {
     kind: ASTStatementKind,
 }
@@ -127,26 +311,56 @@ impl ASTStatement {
     pub fn expression(expr: ASTExpression) -> Self {
         Self::new(ASTStatementKind::Expression(expr))
     }
+
+    pub fn assignment(name: String, value: ASTExpression) -> Self {
+        Self::new(ASTStatementKind::Assignment(ASTAssignmentStatement {
+            name,
+            value,
+        }))
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum ASTExpressionKind {
     Number(ASTNumberExpression),
     Binary(ASTBinaryExpression),
     Parenthesized(ASTParenthesizedExpression),
+    Unary(ASTUnaryExpression),
+    Variable(ASTVariableExpression),
+    Boolean(ASTBooleanExpression),
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct ASTNumberExpression {
-    number: i64,
+    number: f64,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
+pub struct ASTBooleanExpression {
+    value: bool,
+}
+
+#[derive(Debug, PartialEq)]
 pub enum ASTBinaryOperatorKind {
     Plus,
     Minus,
     Multiply,
     Divide,
+    Equals,
+    NotEquals,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    And,
+    Or,
+    Caret,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ASTBinaryOperatorAssociativity {
+    Left,
+    Right,
 }
 
 #[derive(Debug)]
@@ -155,6 +369,14 @@ pub struct ASTBinaryOperator {
     token: Token,
 }
 
+impl PartialEq for ASTBinaryOperator {
+    // Token spans differ across a print/re-parse round trip even when the
+    // operator is unchanged, so equality is defined by `kind` alone.
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+}
+
 impl ASTBinaryOperator {
     fn new(kind: ASTBinaryOperatorKind, token: Token) -> Self {
         Self { kind, token }
@@ -162,35 +384,127 @@ impl ASTBinaryOperator {
 
     pub fn precedence(&self) -> u8 {
         match self.kind {
-            ASTBinaryOperatorKind::Plus | ASTBinaryOperatorKind::Minus => 1,
-            ASTBinaryOperatorKind::Multiply | ASTBinaryOperatorKind::Divide => 2,
+            ASTBinaryOperatorKind::Or => 1,
+            ASTBinaryOperatorKind::And => 2,
+            ASTBinaryOperatorKind::Equals | ASTBinaryOperatorKind::NotEquals => 3,
+            ASTBinaryOperatorKind::Less
+            | ASTBinaryOperatorKind::LessEqual
+            | ASTBinaryOperatorKind::Greater
+            | ASTBinaryOperatorKind::GreaterEqual => 4,
+            ASTBinaryOperatorKind::Plus | ASTBinaryOperatorKind::Minus => 5,
+            ASTBinaryOperatorKind::Multiply | ASTBinaryOperatorKind::Divide => 6,
+            ASTBinaryOperatorKind::Caret => 7,
         }
     }
+
+    pub fn associativity(&self) -> ASTBinaryOperatorAssociativity {
+        match self.kind {
+            ASTBinaryOperatorKind::Caret => ASTBinaryOperatorAssociativity::Right,
+            _ => ASTBinaryOperatorAssociativity::Left,
+        }
+    }
+
+    pub fn span(&self) -> &lexer::TextSpan {
+        &self.token.span
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct ASTBinaryExpression {
     left: Box<ASTExpression>,
     operator: ASTBinaryOperator,
     right: Box<ASTExpression>,
 }
 
+#[derive(Debug, PartialEq)]
+pub enum ASTUnaryOperatorKind {
+    Minus,
+    Bang,
+}
+
 #[derive(Debug)]
+pub struct ASTUnaryOperator {
+    kind: ASTUnaryOperatorKind,
+    token: Token,
+}
+
+impl PartialEq for ASTUnaryOperator {
+    // See ASTBinaryOperator's impl: spans are not part of the AST's meaning.
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+}
+
+impl ASTUnaryOperator {
+    fn new(kind: ASTUnaryOperatorKind, token: Token) -> Self {
+        Self { kind, token }
+    }
+
+    pub fn span(&self) -> &lexer::TextSpan {
+        &self.token.span
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ASTUnaryExpression {
+    operator: ASTUnaryOperator,
+    operand: Box<ASTExpression>,
+}
+
+#[derive(Debug, PartialEq)]
 pub struct ASTParenthesizedExpression {
     expression: Box<ASTExpression>,
 }
 
+#[derive(Debug)]
+pub struct ASTVariableExpression {
+    name: String,
+    token: Token,
+}
+
+impl PartialEq for ASTVariableExpression {
+    // See ASTBinaryOperator's impl: spans are not part of the AST's meaning.
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl ASTVariableExpression {
+    pub fn span(&self) -> &lexer::TextSpan {
+        &self.token.span
+    }
+}
+
 #[derive(Debug)]
 pub struct ASTExpression {
     kind: ASTExpressionKind,
 }
 
+impl PartialEq for ASTExpression {
+    // A `Parenthesized` wrapper carries no meaning of its own, so two
+    // expressions are equal when they agree once redundant parens are
+    // stripped from both sides. This lets the pretty-printer drop parens
+    // that precedence makes unnecessary without that being an AST change.
+    fn eq(&self, other: &Self) -> bool {
+        self.unwrap_parens().kind == other.unwrap_parens().kind
+    }
+}
+
 impl ASTExpression {
     pub fn new(kind: ASTExpressionKind) -> Self {
         Self { kind }
     }
 
-    pub fn number(number: i64) -> Self {
+    fn unwrap_parens(&self) -> &ASTExpression {
+        match &self.kind {
+            ASTExpressionKind::Parenthesized(parenthesized) => {
+                parenthesized.expression.unwrap_parens()
+            }
+            _ => self,
+        }
+    }
+
+    pub fn number(number: f64) -> Self {
         Self::new(ASTExpressionKind::Number(ASTNumberExpression { number }))
     }
 
@@ -209,14 +523,66 @@ impl ASTExpression {
             },
         ))
     }
+
+    pub fn unary(operator: ASTUnaryOperator, operand: ASTExpression) -> Self {
+        Self::new(ASTExpressionKind::Unary(ASTUnaryExpression {
+            operator,
+            operand: Box::new(operand),
+        }))
+    }
+
+    pub fn variable(name: String, token: Token) -> Self {
+        Self::new(ASTExpressionKind::Variable(ASTVariableExpression {
+            name,
+            token,
+        }))
+    }
+
+    pub fn boolean(value: bool) -> Self {
+        Self::new(ASTExpressionKind::Boolean(ASTBooleanExpression { value }))
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::Ast;
+    use crate::ast::lexer::Lexer;
+    use crate::ast::parser::Parser;
 
     #[test]
     fn test_learn() {
         let vec = vec![1, 2, 3, 4, 5];
         println!("{:?}", vec.get(2))
     }
+
+    fn parse(input: &str) -> Ast {
+        let mut lexer = Lexer::new(input);
+        let mut tokens = Vec::new();
+        while let Some(token) = lexer.next_token() {
+            tokens.push(token);
+        }
+        let mut parser = Parser::new(tokens);
+        let mut ast = Ast::new();
+        while let Some(statement) = parser.next_statement() {
+            ast.add_statement(statement);
+        }
+        ast
+    }
+
+    #[test]
+    fn source_printer_round_trips() {
+        let inputs = [
+            "(7 - 8) * -1",
+            "7 - (8 * 1)",
+            "2 ^ 3 ^ 2",
+            "x = 5\ny = x > 4 && x < 10",
+            "!true && (1 == 1)",
+        ];
+        for input in inputs {
+            let original = parse(input);
+            let printed = original.to_source();
+            let reparsed = parse(&printed);
+            assert_eq!(original, reparsed, "input: {}", input);
+        }
+    }
 }
@@ -0,0 +1,159 @@
+use crate::ast::lexer::{Token, TokenKind};
+use crate::ast::{
+    ASTBinaryOperator, ASTBinaryOperatorAssociativity, ASTBinaryOperatorKind, ASTExpression,
+    ASTStatement, ASTUnaryOperator, ASTUnaryOperatorKind,
+};
+
+pub struct Parser {
+    tokens: Vec<Token>,
+    current: usize,
+}
+
+impl Parser {
+    pub fn new(tokens: Vec<Token>) -> Self {
+        Self {
+            tokens: tokens
+                .iter()
+                .filter(|token| token.kind != TokenKind::Whitespace)
+                .cloned()
+                .collect(),
+            current: 0,
+        }
+    }
+
+    pub fn next_statement(&mut self) -> Option<ASTStatement> {
+        if self.is_at_end() {
+            return None;
+        }
+        self.parse_statement()
+    }
+
+    fn parse_statement(&mut self) -> Option<ASTStatement> {
+        if let Some(assignment) = self.parse_assignment_statement() {
+            return Some(assignment);
+        }
+        let expr = self.parse_expression()?;
+        Some(ASTStatement::expression(expr))
+    }
+
+    fn parse_assignment_statement(&mut self) -> Option<ASTStatement> {
+        let name = match &self.current()?.kind {
+            TokenKind::Identifier(name) => name.clone(),
+            _ => return None,
+        };
+        if !matches!(self.peek(1).map(|token| &token.kind), Some(TokenKind::Equals)) {
+            return None;
+        }
+        self.consume();
+        self.consume_and_check(TokenKind::Equals);
+        let value = self.parse_expression()?;
+        Some(ASTStatement::assignment(name, value))
+    }
+
+    fn parse_expression(&mut self) -> Option<ASTExpression> {
+        self.parse_binary_expression(0)
+    }
+
+    fn parse_binary_expression(&mut self, precedence: u8) -> Option<ASTExpression> {
+        let mut left = self.parse_unary_expression()?;
+
+        while let Some(operator) = self.parse_binary_operator() {
+            let operator_precedence = operator.precedence();
+            if operator_precedence <= precedence {
+                break;
+            }
+            self.consume();
+            let next_min_precedence = match operator.associativity() {
+                ASTBinaryOperatorAssociativity::Left => operator_precedence,
+                ASTBinaryOperatorAssociativity::Right => operator_precedence - 1,
+            };
+            let right = self.parse_binary_expression(next_min_precedence)?;
+            left = ASTExpression::binary(left, operator, right);
+        }
+
+        Some(left)
+    }
+
+    fn parse_unary_expression(&mut self) -> Option<ASTExpression> {
+        if let Some(operator) = self.parse_unary_operator() {
+            self.consume();
+            let operand = self.parse_unary_expression()?;
+            return Some(ASTExpression::unary(operator, operand));
+        }
+        self.parse_primary_expression()
+    }
+
+    fn parse_unary_operator(&mut self) -> Option<ASTUnaryOperator> {
+        let token = self.current()?.clone();
+        let kind = match token.kind {
+            TokenKind::Minus => ASTUnaryOperatorKind::Minus,
+            TokenKind::Bang => ASTUnaryOperatorKind::Bang,
+            _ => return None,
+        };
+        Some(ASTUnaryOperator::new(kind, token))
+    }
+
+    fn parse_binary_operator(&mut self) -> Option<ASTBinaryOperator> {
+        let token = self.current()?.clone();
+        let kind = match token.kind {
+            TokenKind::Plus => ASTBinaryOperatorKind::Plus,
+            TokenKind::Minus => ASTBinaryOperatorKind::Minus,
+            TokenKind::Asterisk => ASTBinaryOperatorKind::Multiply,
+            TokenKind::Slash => ASTBinaryOperatorKind::Divide,
+            TokenKind::Caret => ASTBinaryOperatorKind::Caret,
+            TokenKind::EqualsEquals => ASTBinaryOperatorKind::Equals,
+            TokenKind::BangEquals => ASTBinaryOperatorKind::NotEquals,
+            TokenKind::LessThan => ASTBinaryOperatorKind::Less,
+            TokenKind::LessThanEquals => ASTBinaryOperatorKind::LessEqual,
+            TokenKind::GreaterThan => ASTBinaryOperatorKind::Greater,
+            TokenKind::GreaterThanEquals => ASTBinaryOperatorKind::GreaterEqual,
+            TokenKind::AmpersandAmpersand => ASTBinaryOperatorKind::And,
+            TokenKind::PipePipe => ASTBinaryOperatorKind::Or,
+            _ => return None,
+        };
+        Some(ASTBinaryOperator::new(kind, token))
+    }
+
+    fn parse_primary_expression(&mut self) -> Option<ASTExpression> {
+        let token = self.consume()?;
+        match token.kind.clone() {
+            TokenKind::Number(number) => Some(ASTExpression::number(number)),
+            TokenKind::Identifier(name) => Some(ASTExpression::variable(name, token)),
+            TokenKind::True => Some(ASTExpression::boolean(true)),
+            TokenKind::False => Some(ASTExpression::boolean(false)),
+            TokenKind::LeftParen => {
+                let expr = self.parse_expression()?;
+                self.consume_and_check(TokenKind::RightParen);
+                Some(ASTExpression::parenthesized(expr))
+            }
+            _ => None,
+        }
+    }
+
+    fn peek(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.current + offset)
+    }
+
+    fn current(&self) -> Option<&Token> {
+        self.peek(0)
+    }
+
+    fn consume(&mut self) -> Option<Token> {
+        let token = self.current()?.clone();
+        self.current += 1;
+        Some(token)
+    }
+
+    fn consume_and_check(&mut self, kind: TokenKind) -> Option<Token> {
+        let token = self.consume()?;
+        assert_eq!(token.kind, kind);
+        Some(token)
+    }
+
+    fn is_at_end(&self) -> bool {
+        matches!(
+            self.current().map(|token| &token.kind),
+            None | Some(TokenKind::Eof)
+        )
+    }
+}
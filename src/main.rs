@@ -1,3 +1,4 @@
+use crate::ast::codegen::CGenerator;
 use crate::ast::evaluator::ASTEvaluator;
 use crate::ast::lexer::Lexer;
 use crate::ast::parser::Parser;
@@ -6,14 +7,16 @@ use crate::ast::Ast;
 mod ast;
 
 fn main() {
-    let input = "(7 - 8) * -1";
+    let input = "x = 2 ^ 3 ^ 2\ny = x / 2.5";
+    let emit_c = std::env::args().any(|arg| arg == "--emit-c");
+    let print_source = std::env::args().any(|arg| arg == "--print-source");
 
     let mut lexer = Lexer::new(input);
     let mut tokens = Vec::new();
     while let Some(token) = lexer.next_token() {
         tokens.push(token);
     }
-    println!("{:?}", tokens);
+    eprintln!("{:?}", tokens);
 
     let mut ast = Ast::new();
     let mut parser = Parser::new(tokens);
@@ -22,8 +25,23 @@ fn main() {
         ast.add_statement(statement)
     }
 
+    if emit_c {
+        print!("{}", CGenerator::generate(&ast));
+        return;
+    }
+
+    if print_source {
+        print!("{}", ast.to_source());
+        return;
+    }
+
     ast.visualize();
+
     let mut eval = ASTEvaluator::new();
     ast.visit(&mut eval);
-    println!("Result: {}", eval.last_value.unwrap());
+    match eval.last_value {
+        Some(Ok(value)) => println!("Result: {}", value),
+        Some(Err(error)) => eprintln!("Error: {}", error),
+        None => {}
+    }
 }